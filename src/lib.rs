@@ -52,6 +52,214 @@ impl<T: std::hash::Hash> HashCode for T {
     }
 }
 
+std::thread_local! {
+    static MEMOIZE_CACHE: std::cell::RefCell<std::collections::HashMap<u64, Box<dyn std::any::Any>>> =
+        std::cell::RefCell::new(std::collections::HashMap::new());
+}
+
+/// Clear the calling thread's [Memoize] cache.
+///
+/// ```rust
+/// use mictils::{Memoize, clear_memoized};
+///
+/// let val = 12usize.memoize(|v| v * 2);
+/// assert_eq!(24, val);
+///
+/// clear_memoized();
+/// ```
+pub fn clear_memoized() {
+    MEMOIZE_CACHE.with_borrow_mut(|cache| cache.clear());
+}
+
+/// Cache the result of an expensive closure in a per-thread table, keyed by the receiver's
+/// [hashcode](HashCode::hashcode).
+///
+/// The cache lives in a `thread_local!` table for the lifetime of the thread; use
+/// [clear_memoized] to flush it. Because the key is a [HashCode], two inputs whose hashcodes
+/// collide share the same cache slot, so the second one observes the first one's cached value
+/// when both ask for the same result type `R`. If the slot holds a value of a different type,
+/// the closure is run and its result replaces the cached value.
+pub trait Memoize: HashCode {
+    /// Return the cached result for `self` if present, otherwise compute it with `f`, cache it
+    /// and return it.
+    ///
+    /// ```rust
+    /// # use mictils::Memoize;
+    /// let value = String::from("hello").memoize(|s| s.to_ascii_uppercase());
+    ///
+    /// assert_eq!("HELLO", value);
+    /// ```
+    fn memoize<R: Clone + 'static, F: FnOnce(&Self) -> R>(&self, f: F) -> R {
+        let key = self.hashcode();
+
+        if let Some(cached) = MEMOIZE_CACHE.with_borrow(|cache| {
+            cache
+                .get(&key)
+                .and_then(|val| val.downcast_ref::<R>())
+                .cloned()
+        }) {
+            return cached;
+        }
+
+        let result = f(self);
+        MEMOIZE_CACHE.with_borrow_mut(|cache| {
+            cache.insert(key, Box::new(result.clone()));
+        });
+        result
+    }
+}
+
+impl<T: HashCode> Memoize for T {}
+
+/// FNV-1a [Hasher](std::hash::Hasher) used by [StableHashCode].
+///
+/// Unlike [DefaultHasher](std::hash::DefaultHasher), its output is a pure function of the
+/// bytes fed to it, so it does not vary across Rust versions, platforms or process runs.
+struct Fnv64 {
+    state: u64,
+}
+
+impl Default for Fnv64 {
+    fn default() -> Self {
+        Fnv64 {
+            state: 14695981039346656037,
+        }
+    }
+}
+
+impl std::hash::Hasher for Fnv64 {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= *byte as u64;
+            self.state = self.state.wrapping_mul(1099511628211);
+        }
+    }
+
+    // The default `write_*` methods feed bytes in native-endian order, which would make
+    // `stable_hashcode` disagree between little- and big-endian machines. Canonicalize on a
+    // fixed byte order instead, since every integer primitive's `Hash` impl goes through one of
+    // these.
+    fn write_u8(&mut self, i: u8) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write(&(i as u64).to_le_bytes());
+    }
+
+    fn write_i8(&mut self, i: i8) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write(&(i as i64).to_le_bytes());
+    }
+
+    fn finish(&self) -> u64 {
+        self.state
+    }
+}
+
+/// Provide a version-independent hasher.
+///
+/// [HashCode] delegates to [DefaultHasher](std::hash::DefaultHasher) (SipHash), whose output
+/// is explicitly not guaranteed to be stable across Rust versions or platforms. `StableHashCode`
+/// is backed by a hand-rolled FNV-1a hasher instead, so the resulting value is reproducible
+/// across builds and machines, making it suitable for caching keys and on-disk indexes.
+///
+/// # Examples
+/// ```rust
+/// use mictils::StableHashCode;
+///
+/// let str1 = String::from("foo");
+/// let str2 = String::from("foo");
+///
+/// assert_eq!(str1.stable_hashcode(), str2.stable_hashcode());
+/// ```
+pub trait StableHashCode {
+    /// Stable, FNV-1a-backed hasher function.
+    ///
+    /// ```rust
+    /// # use mictils::StableHashCode;
+    /// let text = String::from("StableHashCode");
+    ///
+    /// assert_eq!(text.stable_hashcode(), text.stable_hashcode());
+    /// ```
+    fn stable_hashcode(&self) -> u64;
+}
+
+impl<T: std::hash::Hash> StableHashCode for T {
+    fn stable_hashcode(&self) -> u64 {
+        use std::hash::Hasher;
+
+        let mut hasher = Fnv64::default();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// An owner bundled together with a reference derived from it.
+///
+/// `OwningRef` lets a function hand back a reference that borrows from a value it created
+/// locally (e.g. a lock guard) by keeping the owner alive inside the returned value. It
+/// [Deref](std::ops::Deref)s straight to the borrowed data, so callers use it like a plain
+/// reference.
+///
+/// # Safety
+/// `OwningRef` is constructed from a raw pointer internally: the pointer must point into data
+/// owned by (or reachable through) `owner`, never into a local that outlives the closure that
+/// produced it. The owner is boxed so its address stays stable even if the `OwningRef` itself
+/// is later moved. [Bind::bind_owning] upholds both invariants by deriving the pointer from a
+/// reference to the already-boxed owner.
+pub struct OwningRef<O, T: ?Sized> {
+    // Never read directly; it exists solely to keep the data `reference` points into alive.
+    #[allow(dead_code)]
+    owner: Box<O>,
+    reference: *const T,
+}
+
+impl<O, T: ?Sized> std::ops::Deref for OwningRef<O, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: `reference` was derived from `&owner` and `owner` is kept alive for as long
+        // as `self` exists, so the pointee is still valid.
+        unsafe { &*self.reference }
+    }
+}
+
 /// Kotlin-like trait, but name changed.
 /// not using `let`, it use `bind`.
 ///
@@ -98,6 +306,32 @@ pub trait Bind {
     {
         f(self)
     }
+
+    /// Like [bind](Bind::bind), but for closures that return a reference borrowed from `self`
+    /// instead of an owned value.
+    ///
+    /// Returning such a reference directly does not work because it would borrow from a
+    /// temporary (e.g. a lock guard) that is dropped at the end of the statement. `bind_owning`
+    /// keeps `self` alive by packaging it together with the mapped reference into an
+    /// [OwningRef], which can then be returned out of the enclosing function. `self` is boxed
+    /// before the reference is taken, so its address doesn't change even if the returned
+    /// `OwningRef` is later moved.
+    ///
+    /// ```rust
+    /// # use mictils::Bind;
+    /// let rwlock = std::sync::RwLock::new(String::from("hello, world"));
+    ///
+    /// let greeting = rwlock.read().unwrap().bind_owning(|g| &g[0..5]);
+    /// assert_eq!("hello", &*greeting);
+    /// ```
+    fn bind_owning<T: ?Sized, F: FnOnce(&Self) -> &T>(self, f: F) -> OwningRef<Self, T>
+    where
+        Self: Sized,
+    {
+        let owner = Box::new(self);
+        let reference = f(&owner) as *const T;
+        OwningRef { owner, reference }
+    }
 }
 
 impl<T> Bind for T {}
@@ -142,6 +376,53 @@ pub trait Hold {
 
 impl<T> Hold for T {}
 
+/// Kotlin-like trait, conditional member of the [Bind]/[Hold] family.
+///
+/// Lets a value continue down a fluent pipeline only when a predicate holds, instead of
+/// breaking the chain into an `if`.
+pub trait Filter {
+    /// Returns `Some(self)` if `f` holds for `self`, otherwise `None`.
+    ///
+    /// ```rust
+    /// # use mictils::Filter;
+    /// let value = 4.take_if(|v| *v % 2 == 0);
+    /// assert_eq!(Some(4), value);
+    ///
+    /// let value = 3.take_if(|v| *v % 2 == 0);
+    /// assert_eq!(None, value);
+    /// ```
+    fn take_if<F: FnOnce(&Self) -> bool>(self, f: F) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if f(&self) {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    /// The inverse of [take_if](Filter::take_if): returns `Some(self)` if `f` does **not**
+    /// hold for `self`, otherwise `None`.
+    ///
+    /// ```rust
+    /// # use mictils::Filter;
+    /// let value = 3.take_unless(|v| *v % 2 == 0);
+    /// assert_eq!(Some(3), value);
+    ///
+    /// let value = 4.take_unless(|v| *v % 2 == 0);
+    /// assert_eq!(None, value);
+    /// ```
+    fn take_unless<F: FnOnce(&Self) -> bool>(self, f: F) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.take_if(|val| !f(val))
+    }
+}
+
+impl<T> Filter for T {}
+
 /// Impl [exists_symlink](crate::SymlinkExists::exists_symlink) for [PathBuf](std::path::PathBuf), [Path](std::path::Path) and etc.
 ///
 /// [exists](std::path::Path::exists) follows the symlink and returns the value,  
@@ -161,6 +442,81 @@ where
     }
 }
 
+/// Extends [SymlinkExists] with the rest of the no-follow filesystem queries users actually
+/// need: reading a link's raw target, detecting broken links, and resolving `.`/`..` without
+/// dereferencing the final component.
+///
+/// On Windows, directory junctions and other reparse points are treated as symlinks so that
+/// [is_dangling_symlink](SymlinkInfo::is_dangling_symlink) behaves consistently across
+/// platforms.
+pub trait SymlinkInfo: SymlinkExists {
+    /// Read the raw contents of a symlink, i.e. the target path as written in the link itself,
+    /// without canonicalizing it.
+    fn read_symlink_target(&self) -> std::io::Result<std::path::PathBuf>;
+
+    /// The path is a symlink, but the target it points to does not exist.
+    fn is_dangling_symlink(&self) -> bool;
+
+    /// Resolve `.` and `..` components lexically, without touching the filesystem or
+    /// dereferencing the final component if it is itself a symlink.
+    fn resolve_no_follow(&self) -> std::io::Result<std::path::PathBuf>;
+}
+
+impl<T> SymlinkInfo for T
+where
+    T: std::ops::Deref<Target = std::path::Path>,
+{
+    fn read_symlink_target(&self) -> std::io::Result<std::path::PathBuf> {
+        let path: &std::path::Path = self;
+        std::fs::read_link(path)
+    }
+
+    fn is_dangling_symlink(&self) -> bool {
+        #[cfg(windows)]
+        {
+            use std::os::windows::fs::MetadataExt;
+
+            const FILE_ATTRIBUTE_REPARSE_POINT: u32 = 0x400;
+
+            self.symlink_metadata().is_ok_and(|m| {
+                (m.is_symlink() || m.file_attributes() & FILE_ATTRIBUTE_REPARSE_POINT != 0)
+                    && !self.exists()
+            })
+        }
+
+        #[cfg(not(windows))]
+        {
+            self.exists_symlink() && !self.exists()
+        }
+    }
+
+    fn resolve_no_follow(&self) -> std::io::Result<std::path::PathBuf> {
+        use std::path::Component;
+
+        let mut resolved = std::path::PathBuf::new();
+
+        for component in self.components() {
+            match component {
+                Component::ParentDir => match resolved.components().next_back() {
+                    // Cancels out the preceding normal component.
+                    Some(Component::Normal(_)) => {
+                        resolved.pop();
+                    }
+                    // Already rooted, so `..` has nowhere to go; drop it.
+                    Some(Component::RootDir) | Some(Component::Prefix(_)) => {}
+                    // Nothing to cancel (empty, or a `..` we couldn't cancel either):
+                    // preserve the `..` instead of silently dropping it.
+                    _ => resolved.push(".."),
+                },
+                Component::CurDir => {}
+                other => resolved.push(other.as_os_str()),
+            }
+        }
+
+        Ok(resolved)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -197,6 +553,61 @@ mod tests {
         assert_eq!(val1, val2);
     }
 
+    #[test]
+    fn stable_hashcode_eq_usize() {
+        let val1 = 12usize.stable_hashcode();
+        let val2 = 12usize.stable_hashcode();
+
+        assert_eq!(val1, val2);
+    }
+
+    #[test]
+    fn stable_hashcode_eq_str() {
+        let val1 = String::from("StableHashCode").stable_hashcode();
+        let val2 = String::from("StableHashCode").stable_hashcode();
+
+        assert_eq!(val1, val2);
+    }
+
+    #[test]
+    fn stable_hashcode_eq_vec() {
+        let val1 = vec![1, 2, 3].stable_hashcode();
+        let val2 = vec![1, 2, 3].stable_hashcode();
+
+        assert_eq!(val1, val2);
+    }
+
+    #[test]
+    fn stable_hashcode_differs_by_content() {
+        let val1 = String::from("foo").stable_hashcode();
+        let val2 = String::from("bar").stable_hashcode();
+
+        assert_ne!(val1, val2);
+    }
+
+    #[test]
+    fn stable_hashcode_u64_is_endian_independent() {
+        // The expected value is the FNV-1a fold of the integer's little-endian bytes; it must
+        // stay fixed regardless of the host's native byte order.
+        let val = 0x0102030405060708u64;
+
+        assert_eq!(895447315735140821, val.stable_hashcode());
+    }
+
+    #[test]
+    fn stable_hashcode_eq_struct_with_integer_fields() {
+        #[derive(Hash)]
+        struct Pair {
+            a: u64,
+            b: u32,
+        }
+
+        let val1 = Pair { a: 42, b: 7 }.stable_hashcode();
+        let val2 = Pair { a: 42, b: 7 }.stable_hashcode();
+
+        assert_eq!(val1, val2);
+    }
+
     #[test]
     fn bind_value() {
         let val = String::from("Hello");
@@ -237,6 +648,36 @@ mod tests {
         assert_eq!(String::from("Hello, World"), hold);
     }
 
+    #[test]
+    fn take_if_true() {
+        let value = 4.take_if(|v| *v % 2 == 0);
+        assert_eq!(Some(4), value);
+    }
+
+    #[test]
+    fn take_if_false() {
+        let value = 3.take_if(|v| *v % 2 == 0);
+        assert_eq!(None, value);
+    }
+
+    #[test]
+    fn take_unless_true() {
+        let value = 3.take_unless(|v| *v % 2 == 0);
+        assert_eq!(Some(3), value);
+    }
+
+    #[test]
+    fn take_unless_false() {
+        let value = 4.take_unless(|v| *v % 2 == 0);
+        assert_eq!(None, value);
+    }
+
+    #[test]
+    fn take_if_chained_with_bind() {
+        let value = 4.take_if(|v| *v % 2 == 0).map(|v| v.bind(|v| v * 10));
+        assert_eq!(Some(40), value);
+    }
+
     #[test]
     fn bind_lock_write() {
         let rwlock = std::sync::RwLock::new(String::from("HhEellOo"));
@@ -256,4 +697,169 @@ mod tests {
 
         assert_eq!(String::from("hello, world"), *rwlock.read().unwrap());
     }
+
+    #[test]
+    fn bind_owning_rwlock_guard() {
+        let rwlock = std::sync::RwLock::new(String::from("hello, world"));
+
+        let greeting = rwlock.read().unwrap().bind_owning(|g| &g[0..5]);
+        assert_eq!("hello", &*greeting);
+    }
+
+    #[test]
+    fn bind_owning_box() {
+        let boxed = Box::new(vec![1, 2, 3]);
+
+        let first = boxed.bind_owning(|v| &v[0]);
+        assert_eq!(&1, &*first);
+    }
+
+    #[test]
+    fn bind_owning_vec() {
+        let values = vec![String::from("foo"), String::from("bar")];
+
+        let second = values.bind_owning(|v| v[1].as_str());
+        assert_eq!("bar", &*second);
+    }
+
+    #[test]
+    fn bind_owning_inline_array() {
+        fn make() -> OwningRef<[i32; 64], i32> {
+            let arr: [i32; 64] = [7; 64];
+            arr.bind_owning(|a| &a[0])
+        }
+
+        assert_eq!(7, *make());
+    }
+
+    #[test]
+    fn memoize_caches_result() {
+        clear_memoized();
+
+        let calls = std::cell::Cell::new(0);
+        let compute = |v: &usize| {
+            calls.set(calls.get() + 1);
+            v * 2
+        };
+
+        assert_eq!(24, 12usize.memoize(compute));
+        assert_eq!(24, 12usize.memoize(compute));
+        assert_eq!(1, calls.get());
+    }
+
+    #[test]
+    fn memoize_distinguishes_different_keys() {
+        clear_memoized();
+
+        assert_eq!(2, 1usize.memoize(|v| v * 2));
+        assert_eq!(6, 3usize.memoize(|v| v * 2));
+    }
+
+    #[test]
+    fn clear_memoized_flushes_cache() {
+        clear_memoized();
+
+        let calls = std::cell::Cell::new(0);
+        let compute = |v: &usize| {
+            calls.set(calls.get() + 1);
+            v * 2
+        };
+
+        assert_eq!(10, 5usize.memoize(compute));
+        clear_memoized();
+        assert_eq!(10, 5usize.memoize(compute));
+        assert_eq!(2, calls.get());
+    }
+
+    #[test]
+    fn read_symlink_target_relative() {
+        let dir = tempfile::tempdir().unwrap();
+        let link = dir.path().join("link");
+
+        #[cfg(unix)]
+        std::os::unix::fs::symlink("target", &link).unwrap();
+        #[cfg(windows)]
+        std::os::windows::fs::symlink_file("target", &link).unwrap();
+
+        assert_eq!(
+            std::path::Path::new("target"),
+            link.read_symlink_target().unwrap()
+        );
+    }
+
+    #[test]
+    fn is_dangling_symlink_for_broken_link() {
+        let dir = tempfile::tempdir().unwrap();
+        let link = dir.path().join("broken");
+
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(dir.path().join("missing"), &link).unwrap();
+        #[cfg(windows)]
+        std::os::windows::fs::symlink_file(dir.path().join("missing"), &link).unwrap();
+
+        assert!(link.is_dangling_symlink());
+    }
+
+    #[test]
+    fn is_dangling_symlink_for_valid_link() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("target");
+        std::fs::write(&target, b"hi").unwrap();
+        let link = dir.path().join("link");
+
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+        #[cfg(windows)]
+        std::os::windows::fs::symlink_file(&target, &link).unwrap();
+
+        assert!(!link.is_dangling_symlink());
+    }
+
+    #[test]
+    fn is_dangling_symlink_for_directory_link() {
+        let dir = tempfile::tempdir().unwrap();
+        let target_dir = dir.path().join("target_dir");
+        std::fs::create_dir(&target_dir).unwrap();
+        let link = dir.path().join("dir_link");
+
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&target_dir, &link).unwrap();
+        #[cfg(windows)]
+        std::os::windows::fs::symlink_dir(&target_dir, &link).unwrap();
+
+        assert!(!link.is_dangling_symlink());
+
+        std::fs::remove_dir(&target_dir).unwrap();
+        assert!(link.is_dangling_symlink());
+    }
+
+    #[test]
+    fn resolve_no_follow_collapses_dot_and_dotdot() {
+        let path = std::path::Path::new("/a/b/../c/./d");
+
+        assert_eq!(
+            std::path::Path::new("/a/c/d"),
+            path.resolve_no_follow().unwrap()
+        );
+    }
+
+    #[test]
+    fn resolve_no_follow_preserves_leading_dotdot() {
+        let path = std::path::Path::new("../c/../d");
+
+        assert_eq!(
+            std::path::Path::new("../d"),
+            path.resolve_no_follow().unwrap()
+        );
+    }
+
+    #[test]
+    fn resolve_no_follow_preserves_unresolvable_dotdot() {
+        let path = std::path::Path::new("a/../../b");
+
+        assert_eq!(
+            std::path::Path::new("../b"),
+            path.resolve_no_follow().unwrap()
+        );
+    }
 }
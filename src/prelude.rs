@@ -0,0 +1,9 @@
+//! Re-exports the commonly used traits and types of this crate.
+//!
+//! ```rust
+//! use mictils::prelude::*;
+//! ```
+
+pub use crate::{
+    Bind, Filter, HashCode, Hold, Memoize, OwningRef, StableHashCode, SymlinkExists, SymlinkInfo,
+};